@@ -175,6 +175,9 @@ impl<'a> Excerpt<'a> {
 enum ParseError {
     Empty,
     NotANumber(String),
+    InvalidRadix(u32),
+    InvalidDigit { digit: char, radix: u32 },
+    OutOfRange(String),
 }
 
 impl fmt::Display for ParseError {
@@ -182,24 +185,172 @@ impl fmt::Display for ParseError {
         match self {
             ParseError::Empty => write!(f, "input was empty"),
             ParseError::NotANumber(s) => write!(f, "\"{}\" is not a valid number", s),
+            ParseError::InvalidRadix(radix) => write!(f, "radix {} is out of range (2..=36)", radix),
+            ParseError::InvalidDigit { digit, radix } => {
+                write!(f, "'{}' is not a valid digit in base {}", digit, radix)
+            }
+            ParseError::OutOfRange(s) => write!(f, "\"{}\" does not fit in the target type", s),
         }
     }
 }
 
-fn parse_number(input: &str) -> Result<i64, ParseError> {
+/// Mirrors the standard library's `from_str_radix` behind a trait so
+/// `parse_number` can be generic over the target integer type.
+trait ParseInt: Sized {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ()>;
+}
+
+macro_rules! impl_parse_int {
+    ($($t:ty),*) => {
+        $(
+            impl ParseInt for $t {
+                fn from_str_radix(s: &str, radix: u32) -> Result<Self, ()> {
+                    <$t>::from_str_radix(s, radix).map_err(|_| ())
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_int!(i8, i16, i32, i64, u8, u32, u64, usize);
+
+fn parse_number<T: ParseInt>(input: &str) -> Result<T, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (sign, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+
+    let (radix, digits) = if let Some(digits) = rest.strip_prefix("0x").or_else(|| rest.strip_prefix("0X")) {
+        (16, digits)
+    } else if let Some(digits) = rest.strip_prefix("0o").or_else(|| rest.strip_prefix("0O")) {
+        (8, digits)
+    } else if let Some(digits) = rest.strip_prefix("0b").or_else(|| rest.strip_prefix("0B")) {
+        (2, digits)
+    } else {
+        (10, rest)
+    };
+
+    parse_digits(sign, digits, radix)
+}
+
+/// Parses `input` in the given `radix` (2..=36), accepting an optional
+/// leading sign but no base prefix.
+fn parse_number_radix<T: ParseInt>(input: &str, radix: u32) -> Result<T, ParseError> {
+    if !(2..=36).contains(&radix) {
+        return Err(ParseError::InvalidRadix(radix));
+    }
+
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return Err(ParseError::Empty);
     }
-    trimmed
-        .parse::<i64>()
-        .map_err(|_| ParseError::NotANumber(trimmed.to_string()))
+
+    let (sign, digits) = match trimmed.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+
+    parse_digits(sign, digits, radix)
+}
+
+/// Shared digit-parsing core: validates digits against `radix` one at a time
+/// so the first offending character can be reported, then delegates to
+/// `T::from_str_radix` for the actual conversion, surfacing overflow as
+/// `ParseError::OutOfRange` rather than a generic "not a number".
+fn parse_digits<T: ParseInt>(sign: &str, digits: &str, radix: u32) -> Result<T, ParseError> {
+    if digits.is_empty() {
+        return Err(ParseError::NotANumber(format!("{}{}", sign, digits)));
+    }
+
+    if let Some(digit) = digits.chars().find(|c| c.to_digit(radix).is_none()) {
+        return Err(ParseError::InvalidDigit { digit, radix });
+    }
+
+    let combined = format!("{}{}", sign, digits);
+    T::from_str_radix(&combined, radix).map_err(|_| ParseError::OutOfRange(combined))
 }
 
 fn find_even(numbers: &[i32]) -> Option<i32> {
     numbers.iter().copied().find(|n| n % 2 == 0)
 }
 
+/// A tiny hand-written parser-combinator toolkit: no external deps, just a
+/// cursor over the remaining input and a handful of composable primitives.
+mod parser {
+    use super::ParseError;
+
+    /// The remaining, unconsumed input. Each method advances the cursor.
+    pub struct Tokens<'a> {
+        rest: &'a str,
+    }
+
+    impl<'a> Tokens<'a> {
+        pub fn new(input: &'a str) -> Self {
+            Self { rest: input }
+        }
+
+        /// Consumes and returns the longest prefix matching `pred`.
+        pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+            let end = self.rest.find(|c| !pred(c)).unwrap_or(self.rest.len());
+            let (taken, rest) = self.rest.split_at(end);
+            self.rest = rest;
+            taken
+        }
+
+        /// Consumes `c` if it is next, returning whether it matched.
+        pub fn eat_char(&mut self, c: char) -> bool {
+            match self.rest.strip_prefix(c) {
+                Some(rest) => {
+                    self.rest = rest;
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Parses an optional leading sign followed by one or more decimal
+        /// digits, advancing past whatever was consumed either way.
+        pub fn parse_signed_int(&mut self) -> Result<i64, ParseError> {
+            let negative = self.eat_char('-');
+            if !negative {
+                self.eat_char('+');
+            }
+            let digits = self.take_while(|c| c.is_ascii_digit());
+            if digits.is_empty() {
+                return Err(ParseError::NotANumber(self.rest.to_string()));
+            }
+            let magnitude = digits
+                .parse::<i64>()
+                .map_err(|_| ParseError::NotANumber(digits.to_string()))?;
+            Ok(if negative { -magnitude } else { magnitude })
+        }
+    }
+
+    /// Parses comma-separated integers, trimming whitespace around each
+    /// field. Built on `Tokens` rather than one-shot `str::parse`.
+    pub fn parse_csv_ints(input: &str) -> Result<Vec<i64>, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let mut values = Vec::new();
+
+        loop {
+            tokens.take_while(|c| c.is_whitespace());
+            let n = tokens.parse_signed_int()?;
+            values.push(n);
+            tokens.take_while(|c| c.is_whitespace());
+            if !tokens.eat_char(',') {
+                break;
+            }
+        }
+
+        Ok(values)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pattern Matching
 // ---------------------------------------------------------------------------
@@ -226,7 +377,31 @@ fn describe_option(opt: Option<i32>) -> String {
 // Concurrency
 // ---------------------------------------------------------------------------
 
-fn concurrent_sum(data: &[i32], num_threads: usize) -> i32 {
+/// Sums each overlapping `window`-sized slice of `data`, e.g.
+/// `window_sums(&[1, 2, 3, 4], 2) == [3, 5, 7]`. Returns an empty `Vec` when
+/// `window` is zero or larger than `data`.
+fn window_sums(data: &[i32], window: usize) -> Vec<i32> {
+    window_reduce(data, window, |w| w.iter().sum())
+}
+
+/// Generic windowed reduction built on `slice::windows`: applies `f` to each
+/// overlapping `window`-sized slice of `data`. Returns an empty `Vec` when
+/// `window` is zero or larger than `data`.
+fn window_reduce<T, F>(data: &[T], window: usize, f: F) -> Vec<T>
+where
+    F: Fn(&[T]) -> T,
+{
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+    data.windows(window).map(f).collect()
+}
+
+/// Sums `data` across `num_threads` worker threads. When `window` is `Some`,
+/// each thread instead computes rolling window sums over its chunk (pulling
+/// in `window - 1` extra elements so windows spanning a chunk boundary are
+/// still captured), and the results are returned in order.
+fn concurrent_sum(data: &[i32], num_threads: usize, window: Option<usize>) -> Vec<i32> {
     let chunk_size = (data.len() + num_threads - 1) / num_threads;
     let shared: Arc<Vec<i32>> = Arc::new(data.to_vec());
     let mut handles = Vec::new();
@@ -235,15 +410,28 @@ fn concurrent_sum(data: &[i32], num_threads: usize) -> i32 {
         let data_ref = Arc::clone(&shared);
         handles.push(thread::spawn(move || {
             let start = i * chunk_size;
-            let end = (start + chunk_size).min(data_ref.len());
             if start >= data_ref.len() {
-                return 0;
+                return Vec::new();
+            }
+            match window {
+                None => {
+                    let end = (start + chunk_size).min(data_ref.len());
+                    vec![data_ref[start..end].iter().sum::<i32>()]
+                }
+                Some(window) => {
+                    let end = (start + chunk_size + window - 1).min(data_ref.len());
+                    window_sums(&data_ref[start..end], window)
+                }
             }
-            data_ref[start..end].iter().sum::<i32>()
         }));
     }
 
-    handles.into_iter().map(|h| h.join().unwrap()).sum()
+    let chunks: Vec<Vec<i32>> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    match window {
+        None => vec![chunks.into_iter().flatten().sum()],
+        Some(_) => chunks.into_iter().flatten().collect(),
+    }
 }
 
 fn mutex_counter() -> i32 {
@@ -266,6 +454,116 @@ fn mutex_counter() -> i32 {
     result
 }
 
+// ---------------------------------------------------------------------------
+// Prime Generation
+// ---------------------------------------------------------------------------
+
+mod primes {
+    /// Returns every prime up to and including `limit` using the Sieve of
+    /// Eratosthenes.
+    pub fn primes_up_to(limit: usize) -> Vec<usize> {
+        if limit < 2 {
+            return Vec::new();
+        }
+
+        let mut is_prime = vec![true; limit + 1];
+        is_prime[0] = false;
+        is_prime[1] = false;
+
+        let mut i = 2;
+        while i * i <= limit {
+            if is_prime[i] {
+                let mut multiple = i * i;
+                while multiple <= limit {
+                    is_prime[multiple] = false;
+                    multiple += i;
+                }
+            }
+            i += 1;
+        }
+
+        (2..=limit).filter(|&n| is_prime[n]).collect()
+    }
+
+    /// Returns the `n`th prime (0-indexed, so `nth_prime(0)` is 2), growing
+    /// the sieve bound until enough primes have been found.
+    pub fn nth_prime(n: usize) -> Option<usize> {
+        let mut limit = 16;
+        loop {
+            let found = primes_up_to(limit);
+            if let Some(&prime) = found.get(n) {
+                return Some(prime);
+            }
+            limit *= 2;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Graph Algorithms
+// ---------------------------------------------------------------------------
+
+mod graph {
+    use std::cmp::Ordering;
+    use std::collections::BinaryHeap;
+
+    /// A directed edge to `node` with the given `cost`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Edge {
+        pub node: usize,
+        pub cost: u32,
+    }
+
+    /// An entry in the priority queue. `Ord` is reversed so `BinaryHeap`,
+    /// which is a max-heap, pops the lowest-cost state first.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+    struct State {
+        cost: u32,
+        position: usize,
+    }
+
+    impl Ord for State {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.cost.cmp(&self.cost)
+        }
+    }
+
+    impl PartialOrd for State {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Dijkstra's algorithm over an adjacency list, returning the cheapest
+    /// cost from `start` to `goal`, or `None` if `goal` is unreachable.
+    pub fn shortest_path(adj: &[Vec<Edge>], start: usize, goal: usize) -> Option<u32> {
+        let mut dist = vec![u32::MAX; adj.len()];
+        let mut heap = BinaryHeap::new();
+
+        dist[start] = 0;
+        heap.push(State { cost: 0, position: start });
+
+        while let Some(State { cost, position }) = heap.pop() {
+            if position == goal {
+                return Some(cost);
+            }
+            if cost > dist[position] {
+                continue;
+            }
+
+            for edge in &adj[position] {
+                let next = State { cost: cost + edge.cost, position: edge.node };
+                if next.cost < dist[next.position] {
+                    dist[next.position] = next.cost;
+                    heap.push(next);
+                }
+            }
+        }
+
+        None
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Main — runs all demonstrations
 // ---------------------------------------------------------------------------
@@ -352,14 +650,19 @@ fn main() {
 
     // --- Error handling ----------------------------------------------------
     println!("\n-- Error Handling --");
-    let inputs = ["42", "", "abc", " -7 "];
+    let inputs = ["42", "", "abc", " -7 ", "0x1F", "0o17", "0b101"];
     for input in inputs {
-        match parse_number(input) {
+        match parse_number::<i64>(input) {
             Ok(n) => println!("  parse(\"{}\") = {}", input, n),
             Err(e) => println!("  parse(\"{}\") error: {}", input, e),
         }
     }
 
+    match parse_number_radix::<i64>("ff", 16) {
+        Ok(n) => println!("  parse_number_radix(\"ff\", 16) = {}", n),
+        Err(e) => println!("  parse_number_radix(\"ff\", 16) error: {}", e),
+    }
+
     let nums = [1, 3, 5, 8, 9];
     match find_even(&nums) {
         Some(n) => println!("  First even in {:?}: {}", nums, n),
@@ -370,6 +673,11 @@ fn main() {
     let doubled: i32 = find_even(&[1, 3, 5]).map(|n| n * 2).unwrap_or(0);
     println!("  Doubled first even (or 0): {}", doubled);
 
+    match parser::parse_csv_ints("1, -2, 3") {
+        Ok(values) => println!("  parse_csv_ints(\"1, -2, 3\") = {:?}", values),
+        Err(e) => println!("  parse_csv_ints(\"1, -2, 3\") error: {}", e),
+    }
+
     // --- Traits and generics -----------------------------------------------
     println!("\n-- Traits and Generics --");
     let article = Article {
@@ -391,12 +699,33 @@ fn main() {
     // --- Concurrency -------------------------------------------------------
     println!("\n-- Concurrency --");
     let data: Vec<i32> = (1..=100).collect();
-    let total = concurrent_sum(&data, 4);
+    let total = concurrent_sum(&data, 4, None)[0];
     println!("  Sum of 1..=100 using 4 threads: {}", total);
 
+    let rolling = concurrent_sum(&data[..10], 3, Some(3));
+    println!("  Rolling window(3) sums of 1..=10 using 3 threads: {:?}", rolling);
+
     let final_count = mutex_counter();
     println!("  Mutex counter after 5 threads: {}", final_count);
 
+    // --- Prime generation ----------------------------------------------------
+    println!("\n-- Prime Generation --");
+    println!("  primes up to 30: {:?}", primes::primes_up_to(30));
+    println!("  10th prime: {:?}", primes::nth_prime(9));
+
+    // --- Graph algorithms ------------------------------------------------
+    println!("\n-- Graph Algorithms --");
+    let adj: Vec<Vec<graph::Edge>> = vec![
+        vec![graph::Edge { node: 1, cost: 4 }, graph::Edge { node: 2, cost: 1 }],
+        vec![graph::Edge { node: 3, cost: 1 }],
+        vec![graph::Edge { node: 1, cost: 2 }, graph::Edge { node: 3, cost: 5 }],
+        vec![],
+    ];
+    match graph::shortest_path(&adj, 0, 3) {
+        Some(cost) => println!("  shortest path 0 → 3: {}", cost),
+        None => println!("  no path from 0 to 3"),
+    }
+
     println!("\nDone.");
 }
 
@@ -447,19 +776,61 @@ mod tests {
 
     #[test]
     fn test_parse_number_ok() {
-        assert_eq!(parse_number("42").unwrap(), 42);
-        assert_eq!(parse_number(" -7 ").unwrap(), -7);
+        assert_eq!(parse_number::<i64>("42").unwrap(), 42);
+        assert_eq!(parse_number::<i64>(" -7 ").unwrap(), -7);
     }
 
     #[test]
     fn test_parse_number_empty() {
-        assert!(matches!(parse_number(""), Err(ParseError::Empty)));
-        assert!(matches!(parse_number("   "), Err(ParseError::Empty)));
+        assert!(matches!(parse_number::<i64>(""), Err(ParseError::Empty)));
+        assert!(matches!(parse_number::<i64>("   "), Err(ParseError::Empty)));
     }
 
     #[test]
     fn test_parse_number_invalid() {
-        assert!(matches!(parse_number("abc"), Err(ParseError::NotANumber(_))));
+        assert!(matches!(parse_number::<i64>("abc"), Err(ParseError::InvalidDigit { digit: 'a', radix: 10 })));
+    }
+
+    #[test]
+    fn test_parse_number_radix_prefixes() {
+        assert_eq!(parse_number::<i64>("0x1F").unwrap(), 31);
+        assert_eq!(parse_number::<i64>("0o17").unwrap(), 15);
+        assert_eq!(parse_number::<i64>("0b101").unwrap(), 5);
+        assert_eq!(parse_number::<i64>("-0x10").unwrap(), -16);
+    }
+
+    #[test]
+    fn test_parse_number_radix_explicit() {
+        assert_eq!(parse_number_radix::<i64>("ff", 16).unwrap(), 255);
+        assert_eq!(parse_number_radix::<i64>("-101", 2).unwrap(), -5);
+    }
+
+    #[test]
+    fn test_parse_number_radix_invalid_radix() {
+        assert!(matches!(parse_number_radix::<i64>("10", 1), Err(ParseError::InvalidRadix(1))));
+        assert!(matches!(parse_number_radix::<i64>("10", 37), Err(ParseError::InvalidRadix(37))));
+    }
+
+    #[test]
+    fn test_parse_number_invalid_digit() {
+        let err = parse_number_radix::<i64>("1g", 16).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidDigit { digit: 'g', radix: 16 }));
+    }
+
+    #[test]
+    fn test_parse_number_generic_over_types() {
+        assert_eq!(parse_number::<i8>("42").unwrap(), 42i8);
+        assert_eq!(parse_number::<i16>("42").unwrap(), 42i16);
+        assert_eq!(parse_number::<i32>("42").unwrap(), 42i32);
+        assert_eq!(parse_number::<u32>("255").unwrap(), 255u32);
+        assert_eq!(parse_number::<u64>("255").unwrap(), 255u64);
+        assert_eq!(parse_number::<usize>("255").unwrap(), 255usize);
+    }
+
+    #[test]
+    fn test_parse_number_out_of_range() {
+        assert!(matches!(parse_number::<u8>("300"), Err(ParseError::OutOfRange(_))));
+        assert!(matches!(parse_number::<i8>("-200"), Err(ParseError::OutOfRange(_))));
     }
 
     #[test]
@@ -486,8 +857,33 @@ mod tests {
     #[test]
     fn test_concurrent_sum() {
         let data: Vec<i32> = (1..=100).collect();
-        assert_eq!(concurrent_sum(&data, 4), 5050);
-        assert_eq!(concurrent_sum(&data, 1), 5050);
+        assert_eq!(concurrent_sum(&data, 4, None), vec![5050]);
+        assert_eq!(concurrent_sum(&data, 1, None), vec![5050]);
+    }
+
+    #[test]
+    fn test_concurrent_sum_windowed() {
+        let data: Vec<i32> = (1..=10).collect();
+        let expected = window_sums(&data, 3);
+        assert_eq!(concurrent_sum(&data, 3, Some(3)), expected);
+        assert_eq!(concurrent_sum(&data, 1, Some(3)), expected);
+    }
+
+    #[test]
+    fn test_window_sums() {
+        assert_eq!(window_sums(&[1, 2, 3, 4], 2), vec![3, 5, 7]);
+    }
+
+    #[test]
+    fn test_window_sums_edge_cases() {
+        assert_eq!(window_sums(&[1, 2, 3], 0), Vec::<i32>::new());
+        assert_eq!(window_sums(&[1, 2, 3], 4), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_window_reduce_generic() {
+        let maxes = window_reduce(&[1, 5, 2, 8, 3], 2, |w| *w.iter().max().unwrap());
+        assert_eq!(maxes, vec![5, 5, 8, 8]);
     }
 
     #[test]
@@ -512,4 +908,91 @@ mod tests {
         assert!(tweet.summarize().contains("@user"));
         assert_eq!(tweet.headline(), "(Read more...)");
     }
+
+    #[test]
+    fn test_primes_up_to() {
+        assert_eq!(
+            primes::primes_up_to(30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn test_primes_up_to_small_limit() {
+        assert_eq!(primes::primes_up_to(1), Vec::<usize>::new());
+        assert_eq!(primes::primes_up_to(0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_nth_prime() {
+        assert_eq!(primes::nth_prime(0), Some(2));
+        assert_eq!(primes::nth_prime(5), Some(13));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        use graph::Edge;
+
+        let adj: Vec<Vec<Edge>> = vec![
+            vec![Edge { node: 1, cost: 4 }, Edge { node: 2, cost: 1 }],
+            vec![Edge { node: 3, cost: 1 }],
+            vec![Edge { node: 1, cost: 2 }, Edge { node: 3, cost: 5 }],
+            vec![],
+        ];
+
+        assert_eq!(graph::shortest_path(&adj, 0, 3), Some(4));
+        assert_eq!(graph::shortest_path(&adj, 0, 0), Some(0));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        use graph::Edge;
+
+        let adj: Vec<Vec<Edge>> = vec![vec![Edge { node: 1, cost: 1 }], vec![], vec![]];
+        assert_eq!(graph::shortest_path(&adj, 0, 2), None);
+    }
+
+    #[test]
+    fn test_shortest_path_tie_breaking() {
+        use graph::Edge;
+
+        // Two equal-cost paths from 0 to 3: via 1 (cost 5) and via 2 (cost 5).
+        let adj: Vec<Vec<Edge>> = vec![
+            vec![Edge { node: 1, cost: 2 }, Edge { node: 2, cost: 3 }],
+            vec![Edge { node: 3, cost: 3 }],
+            vec![Edge { node: 3, cost: 2 }],
+            vec![],
+        ];
+
+        assert_eq!(graph::shortest_path(&adj, 0, 3), Some(5));
+    }
+
+    #[test]
+    fn test_parse_csv_ints() {
+        assert_eq!(parser::parse_csv_ints("1,2,3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parser::parse_csv_ints(" 1 , -2 , 3 ").unwrap(), vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn test_parse_csv_ints_empty_field() {
+        assert!(matches!(parser::parse_csv_ints("1,,3"), Err(ParseError::NotANumber(_))));
+    }
+
+    #[test]
+    fn test_parse_csv_ints_trailing_comma() {
+        assert!(matches!(parser::parse_csv_ints("1,2,"), Err(ParseError::NotANumber(_))));
+    }
+
+    #[test]
+    fn test_parse_csv_ints_invalid_token() {
+        assert!(matches!(parser::parse_csv_ints("1,abc"), Err(ParseError::NotANumber(_))));
+    }
+
+    #[test]
+    fn test_tokens_take_while_and_eat_char() {
+        let mut tokens = parser::Tokens::new("123abc");
+        assert_eq!(tokens.take_while(|c| c.is_ascii_digit()), "123");
+        assert!(tokens.eat_char('a'));
+        assert!(!tokens.eat_char('x'));
+    }
 }